@@ -0,0 +1,59 @@
+// surfman/surfman/src/error.rs
+//
+//! Error types.
+
+use std::fmt::{self, Display, Formatter};
+
+/// An error produced by a windowing/GL API, wrapped opaquely so that callers can display it
+/// without matching on platform-specific codes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct WindowingApiError(pub(crate) u32);
+
+impl Display for WindowingApiError {
+    fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
+        write!(formatter, "windowing API error 0x{:x}", self.0)
+    }
+}
+
+/// Errors that `surfman` can produce.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// The requested GL version (or combination of major/minor version) isn't supported.
+    UnsupportedGLVersion,
+    /// The requested GL profile (e.g. compatibility, or a feature that isn't backed by the
+    /// necessary extension) isn't supported on this display.
+    UnsupportedGLProfile,
+    /// `eglChooseConfig`/`eglGetConfigs` failed while selecting a pixel format.
+    PixelFormatSelectionFailed(WindowingApiError),
+    /// No pixel format matching the requested attributes could be found.
+    NoPixelFormatFound,
+    /// `eglCreateContext` failed.
+    ContextCreationFailed(WindowingApiError),
+    /// `eglMakeCurrent` failed.
+    MakeCurrentFailed(WindowingApiError),
+    /// The context requested to share GL objects with was created from a different display, or
+    /// with a different config, than the context being created.
+    IncompatibleSharedContext,
+    /// `eglBindAPI` failed, meaning this display can't serve the requested client API (desktop
+    /// OpenGL or OpenGL ES).
+    UnsupportedGLApi,
+}
+
+impl Display for Error {
+    fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
+        match *self {
+            Error::UnsupportedGLVersion => write!(formatter, "unsupported GL version"),
+            Error::UnsupportedGLProfile => write!(formatter, "unsupported GL profile"),
+            Error::PixelFormatSelectionFailed(err) => {
+                write!(formatter, "pixel format selection failed: {}", err)
+            }
+            Error::NoPixelFormatFound => write!(formatter, "no pixel format found"),
+            Error::ContextCreationFailed(err) => write!(formatter, "context creation failed: {}", err),
+            Error::MakeCurrentFailed(err) => write!(formatter, "make current failed: {}", err),
+            Error::IncompatibleSharedContext => {
+                write!(formatter, "the context to share with is incompatible with this one")
+            }
+            Error::UnsupportedGLApi => write!(formatter, "unsupported client GL API"),
+        }
+    }
+}
@@ -0,0 +1,95 @@
+// surfman/surfman/src/lib.rs
+//
+//! `surfman` is a cross-platform, low-level toolkit for GPU surface management.
+
+use bitflags::bitflags;
+
+mod error;
+pub mod platform;
+
+pub use crate::error::{Error, WindowingApiError};
+
+#[allow(non_snake_case)]
+pub(crate) mod egl {
+    include!(concat!(env!("OUT_DIR"), "/egl_bindings.rs"));
+}
+
+#[allow(non_snake_case)]
+pub(crate) mod gl {
+    include!(concat!(env!("OUT_DIR"), "/gl_bindings.rs"));
+}
+
+/// The client API that a context was (or should be) created against.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GLApi {
+    /// OpenGL ES, via `eglBindAPI(EGL_OPENGL_ES_API)`.
+    GLES,
+    /// Desktop OpenGL, via `eglBindAPI(EGL_OPENGL_API)`.
+    GL,
+}
+
+/// A GL (or GLES) version, e.g. 3.0 or 4.1.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct GLVersion {
+    /// The major version number (e.g. the `3` in `3.0`).
+    pub major: u8,
+    /// The minor version number (e.g. the `0` in `3.0`).
+    pub minor: u8,
+}
+
+impl GLVersion {
+    /// Creates a new `GLVersion` with the given major and minor version numbers.
+    pub fn new(major: u8, minor: u8) -> GLVersion {
+        GLVersion { major, minor }
+    }
+
+    /// Queries the version of the context that's currently current.
+    pub(crate) fn current(gl: &gl::Gl) -> GLVersion {
+        unsafe {
+            let mut major = 0;
+            let mut minor = 0;
+            gl.GetIntegerv(gl::MAJOR_VERSION, &mut major);
+            gl.GetIntegerv(gl::MINOR_VERSION, &mut minor);
+            GLVersion::new(major as u8, minor as u8)
+        }
+    }
+}
+
+bitflags! {
+    /// Attributes that can be requested of (or reported back about) a GL context.
+    pub struct ContextAttributeFlags: u8 {
+        /// The context has an alpha channel.
+        const ALPHA = 0x01;
+        /// The context has a depth buffer.
+        const DEPTH = 0x02;
+        /// The context has a stencil buffer.
+        const STENCIL = 0x04;
+        /// The context is a compatibility-profile context, as opposed to a core-profile one.
+        const COMPATIBILITY_PROFILE = 0x08;
+        /// The context can recover from a GPU reset instead of becoming permanently unusable.
+        const ROBUST = 0x10;
+        /// The context can be made current without a bound surface, instead of needing a dummy
+        /// pbuffer to serve as its default framebuffer.
+        const SURFACELESS = 0x20;
+        /// The context's default framebuffer has more than 8 bits per color channel.
+        const HIGH_BIT_DEPTH = 0x40;
+        /// The context's default framebuffer (and any surfaces created for it) should be
+        /// sRGB-encoded.
+        const SRGB = 0x80;
+    }
+}
+
+/// The attributes that a context was (or should be) created with.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ContextAttributes {
+    /// The boolean attributes requested of (or reported back about) this context.
+    pub flags: ContextAttributeFlags,
+    /// The GL (or GLES) version requested of (or reported back about) this context.
+    pub version: GLVersion,
+    /// The number of samples per pixel requested of (or reported back about) this context, or 0
+    /// if multisampling wasn't requested.
+    pub samples: u8,
+    /// The client API (desktop OpenGL or OpenGL ES) requested of (or reported back about) this
+    /// context.
+    pub api: GLApi,
+}
@@ -5,17 +5,38 @@
 use crate::egl::types::{EGLConfig, EGLContext, EGLDisplay, EGLSurface, EGLint};
 use crate::egl;
 use crate::gl::Gl;
-use crate::{ContextAttributeFlags, ContextAttributes, Error, GLVersion};
+use crate::{ContextAttributeFlags, ContextAttributes, Error, GLApi, GLVersion};
 use super::device::EGL_FUNCTIONS;
 use super::error::ToWindowingApiError;
 
-use std::ffi::CString;
+use std::ffi::{CStr, CString};
 use std::os::raw::{c_char, c_void};
 use std::ptr;
 
 #[allow(dead_code)]
 const DUMMY_PBUFFER_SIZE: EGLint = 16;
 const RGB_CHANNEL_BIT_DEPTH: EGLint = 8;
+const HIGH_BIT_DEPTH_RGB_CHANNEL_BIT_DEPTH: EGLint = 10;
+const HIGH_BIT_DEPTH_ALPHA_SIZE: EGLint = 2;
+
+// EGL_KHR_create_context, which lets us request a specific minor GL version (and more besides).
+const EGL_CONTEXT_MINOR_VERSION_KHR: EGLint = 0x30FB;
+
+// EGL_EXT_create_context_robustness, which lets us request a context that can recover from GPU
+// resets instead of becoming permanently unusable.
+const EGL_CONTEXT_OPENGL_ROBUST_ACCESS_EXT: EGLint = 0x30BF;
+const EGL_CONTEXT_OPENGL_RESET_NOTIFICATION_STRATEGY_EXT: EGLint = 0x3138;
+const EGL_LOSE_CONTEXT_ON_RESET_EXT: EGLint = 0x31BF;
+
+// EGL_KHR_gl_colorspace, used at surface-creation time to request an sRGB-encoded default
+// framebuffer for contexts created with `ContextAttributeFlags::SRGB`.
+const EGL_GL_COLORSPACE_KHR: EGLint = 0x309D;
+const EGL_GL_COLORSPACE_SRGB_KHR: EGLint = 0x3089;
+
+// EGL_KHR_create_context profile mask, used to request a desktop OpenGL core profile. (Desktop
+// OpenGL itself is part of EGL 1.4 via `eglBindAPI`; only the profile mask needs the extension.)
+const EGL_CONTEXT_OPENGL_PROFILE_MASK_KHR: EGLint = 0x30FD;
+const EGL_CONTEXT_OPENGL_CORE_PROFILE_BIT_KHR: EGLint = 0x0000_0001;
 
 /// Wrapper for a native `EGLContext`.
 #[derive(Clone, Copy)]
@@ -35,6 +56,28 @@ pub struct NativeContext {
 pub struct ContextDescriptor {
     pub(crate) egl_config_id: EGLint,
     pub(crate) gl_version: GLVersion,
+    // Whether the display advertises `EGL_KHR_create_context`, so that `create_context` can
+    // request a GL version with a minor component instead of just a major one. Only the
+    // extension string is checked; an EGL 1.5 display that doesn't list the extension counts
+    // as unsupported.
+    pub(crate) supports_context_minor_version: bool,
+    // Whether the context should be created with `EGL_EXT_create_context_robustness`, so that
+    // GPU resets can be detected and recovered from instead of leaving the context unusable.
+    pub(crate) robust: bool,
+    // Whether the context can be made current without a bound surface, via
+    // `EGL_KHR_surfaceless_context`, so we don't have to allocate a dummy pbuffer for it.
+    pub(crate) surfaceless: bool,
+    // Whether surfaces created for this context should request sRGB-encoded framebuffers via
+    // `EGL_KHR_gl_colorspace`. This isn't a config attribute, so it's threaded through to
+    // surface creation rather than baked into `egl_config_id`.
+    pub(crate) srgb: bool,
+    // The number of samples per pixel that the config was chosen to support, or 0 if
+    // multisampling wasn't requested.
+    pub(crate) samples: u8,
+    // Whether this is a desktop OpenGL core-profile context or an OpenGL ES context. EGL can
+    // serve both, but the client API has to be bound with `eglBindAPI` before creating the
+    // context, so we need to remember which one this descriptor is for.
+    pub(crate) api: GLApi,
 }
 
 #[must_use]
@@ -87,26 +130,75 @@ impl ContextDescriptor {
             return Err(Error::UnsupportedGLProfile);
         }
 
-        // FIXME(pcwalton): Unfortunately, EGL 1.5, which is not particularly widespread, is needed
-        // to specify a minor version. Until I see EGL 1.5 in the wild, let's just cap our OpenGL
-        // ES version to 3.0.
-        if attributes.version.major > 3 ||
-                attributes.version.major == 3 && attributes.version.minor > 0 {
-            return Err(Error::UnsupportedGLVersion);
+        // EGL 1.4 (without `EGL_KHR_create_context`) has no way to specify a minor GL version, so
+        // without that extension we have to cap ourselves to OpenGL ES 3.0. With it, we can go up
+        // to 3.2. This cap is specific to OpenGL ES: desktop OpenGL core profiles routinely go up
+        // to 4.x, and are handled by the `GLApi::GL` branch below instead.
+        let extensions = egl_extensions(egl_display);
+        let supports_context_minor_version =
+            extension_supported(&extensions, "EGL_KHR_create_context");
+        if attributes.api == GLApi::GLES {
+            let max_minor_version = if supports_context_minor_version { 2 } else { 0 };
+            if attributes.version.major > 3 ||
+                    attributes.version.major == 3 && attributes.version.minor > max_minor_version {
+                return Err(Error::UnsupportedGLVersion);
+            }
+        } else {
+            // Desktop OpenGL core profiles top out around 4.6; reject anything past that as
+            // nonsense instead of silently accepting it and failing later, less clearly, at
+            // context-creation time.
+            if attributes.version.major > 4 ||
+                    attributes.version.major == 4 && attributes.version.minor > 6 {
+                return Err(Error::UnsupportedGLVersion);
+            }
+        }
+
+        let robust = flags.contains(ContextAttributeFlags::ROBUST);
+        if robust && !extension_supported(&extensions, "EGL_EXT_create_context_robustness") {
+            return Err(Error::UnsupportedGLProfile);
         }
 
-        let alpha_size   = if flags.contains(ContextAttributeFlags::ALPHA)   { 8  } else { 0 };
+        let surfaceless = flags.contains(ContextAttributeFlags::SURFACELESS);
+        if surfaceless && !extension_supported(&extensions, "EGL_KHR_surfaceless_context") {
+            return Err(Error::UnsupportedGLProfile);
+        }
+
+        let high_bit_depth = flags.contains(ContextAttributeFlags::HIGH_BIT_DEPTH);
+        let rgb_channel_bit_depth = if high_bit_depth {
+            HIGH_BIT_DEPTH_RGB_CHANNEL_BIT_DEPTH
+        } else {
+            RGB_CHANNEL_BIT_DEPTH
+        };
+
+        let alpha_size = if !flags.contains(ContextAttributeFlags::ALPHA) {
+            0
+        } else if high_bit_depth {
+            HIGH_BIT_DEPTH_ALPHA_SIZE
+        } else {
+            8
+        };
         let depth_size   = if flags.contains(ContextAttributeFlags::DEPTH)   { 24 } else { 0 };
         let stencil_size = if flags.contains(ContextAttributeFlags::STENCIL) { 8  } else { 0 };
+        let samples = attributes.samples;
+        let srgb = flags.contains(ContextAttributeFlags::SRGB);
+
+        let api = attributes.api;
+        if api == GLApi::GL && !supports_context_minor_version {
+            // Requesting a core profile relies on `EGL_CONTEXT_OPENGL_PROFILE_MASK_KHR`, which
+            // `EGL_KHR_create_context` provides.
+            return Err(Error::UnsupportedGLProfile);
+        }
 
         // Create required config attributes.
         //
         // We check these separately because `eglChooseConfig` on its own might give us 32-bit
-        // color when 24-bit color is requested, and that can break code.
+        // color when 24-bit color is requested, and that can break code. Widened to the
+        // requested channel depth so a 10-bit-per-channel request can't silently be downgraded
+        // to 8-bit either.
         let required_config_attributes = [
-            egl::RED_SIZE as EGLint,    RGB_CHANNEL_BIT_DEPTH,
-            egl::GREEN_SIZE as EGLint,  RGB_CHANNEL_BIT_DEPTH,
-            egl::BLUE_SIZE as EGLint,   RGB_CHANNEL_BIT_DEPTH,
+            egl::RED_SIZE as EGLint,    rgb_channel_bit_depth,
+            egl::GREEN_SIZE as EGLint,  rgb_channel_bit_depth,
+            egl::BLUE_SIZE as EGLint,   rgb_channel_bit_depth,
         ];
 
         // Create config attributes.
@@ -116,6 +208,19 @@ impl ContextDescriptor {
             egl::DEPTH_SIZE as EGLint,      depth_size,
             egl::STENCIL_SIZE as EGLint,    stencil_size,
         ]);
+        if samples > 0 {
+            requested_config_attributes.extend_from_slice(&[
+                egl::SAMPLE_BUFFERS as EGLint,  1,
+                egl::SAMPLES as EGLint,         samples as EGLint,
+            ]);
+        }
+        if api == GLApi::GL {
+            // The default `EGL_RENDERABLE_TYPE` only matches GLES-capable configs, so desktop
+            // OpenGL has to ask for `EGL_OPENGL_BIT` explicitly.
+            requested_config_attributes.extend_from_slice(&[
+                egl::RENDERABLE_TYPE as EGLint, egl::OPENGL_BIT as EGLint,
+            ]);
+        }
         requested_config_attributes.extend_from_slice(extra_config_attributes);
         requested_config_attributes.extend_from_slice(&[egl::NONE as EGLint, 0, 0, 0]);
 
@@ -163,7 +268,23 @@ impl ContextDescriptor {
             let egl_config_id = get_config_attr(egl_display, egl_config, egl::CONFIG_ID as EGLint);
             let gl_version = attributes.version;
 
-            Ok(ContextDescriptor { egl_config_id, gl_version })
+            // `SAMPLE_BUFFERS`/`SAMPLES` are only in `requested_config_attributes`, not the
+            // exact-match `required_config_attributes` sanitization above, so `eglChooseConfig`
+            // may have granted a config with a different sample count than what was requested.
+            // Store the sample count the config actually has, not the one asked for.
+            let granted_samples =
+                get_config_attr(egl_display, egl_config, egl::SAMPLES as EGLint) as u8;
+
+            Ok(ContextDescriptor {
+                egl_config_id,
+                gl_version,
+                supports_context_minor_version,
+                robust,
+                surfaceless,
+                srgb,
+                samples: granted_samples,
+                api,
+            })
         })
     }
 
@@ -172,12 +293,39 @@ impl ContextDescriptor {
                                           egl_context: EGLContext)
                                           -> ContextDescriptor {
         let egl_config_id = get_context_attr(egl_display, egl_context, egl::CONFIG_ID as EGLint);
+        let extensions = egl_extensions(egl_display);
+        let supports_context_minor_version =
+            extension_supported(&extensions, "EGL_KHR_create_context");
+        // `EGL_CONTEXT_OPENGL_ROBUST_ACCESS_EXT` is a create-time attribute, not one of the
+        // attributes EGL guarantees is queryable back, so implementations may legitimately fail
+        // this query even when the extension is advertised; treat that as "not robust" rather
+        // than asserting.
+        let robust = extension_supported(&extensions, "EGL_EXT_create_context_robustness") &&
+            try_get_context_attr(egl_display,
+                                 egl_context,
+                                 EGL_CONTEXT_OPENGL_ROBUST_ACCESS_EXT).unwrap_or(0) != 0;
+        let egl_config = egl_config_from_id(egl_display, egl_config_id);
+        let samples = get_config_attr(egl_display, egl_config, egl::SAMPLES as EGLint) as u8;
 
         EGL_FUNCTIONS.with(|egl| {
             let _guard = CurrentContextGuard::new();
             egl.MakeCurrent(egl_display, egl::NO_SURFACE, egl::NO_SURFACE, egl_context);
             let gl_version = GLVersion::current(gl);
-            ContextDescriptor { egl_config_id, gl_version }
+            // We didn't create this context, so we have no opinion on whether it should be made
+            // current without a surface; always allocate a dummy pbuffer for it.
+            ContextDescriptor {
+                egl_config_id,
+                gl_version,
+                supports_context_minor_version,
+                robust,
+                surfaceless: false,
+                srgb: false,
+                samples,
+                // We didn't create this context either, so we can't know for sure which client
+                // API it was bound against; assume GLES, which is what every current caller of
+                // this constructor uses.
+                api: GLApi::GLES,
+            }
         })
     }
 
@@ -205,18 +353,32 @@ impl ContextDescriptor {
     pub(crate) unsafe fn attributes(&self, egl_display: EGLDisplay) -> ContextAttributes {
         let egl_config = egl_config_from_id(egl_display, self.egl_config_id);
 
+        let red_size = get_config_attr(egl_display, egl_config, egl::RED_SIZE as EGLint);
         let alpha_size = get_config_attr(egl_display, egl_config, egl::ALPHA_SIZE as EGLint);
         let depth_size = get_config_attr(egl_display, egl_config, egl::DEPTH_SIZE as EGLint);
         let stencil_size = get_config_attr(egl_display, egl_config, egl::STENCIL_SIZE as EGLint);
+        let samples = get_config_attr(egl_display, egl_config, egl::SAMPLES as EGLint);
 
         // Convert to `surfman` context attribute flags.
         let mut attribute_flags = ContextAttributeFlags::empty();
         attribute_flags.set(ContextAttributeFlags::ALPHA, alpha_size != 0);
         attribute_flags.set(ContextAttributeFlags::DEPTH, depth_size != 0);
         attribute_flags.set(ContextAttributeFlags::STENCIL, stencil_size != 0);
-
-        // Create appropriate context attributes.
-        ContextAttributes { flags: attribute_flags, version: self.gl_version }
+        attribute_flags.set(ContextAttributeFlags::ROBUST, self.robust);
+        attribute_flags.set(ContextAttributeFlags::SURFACELESS, self.surfaceless);
+        attribute_flags.set(ContextAttributeFlags::HIGH_BIT_DEPTH,
+                            red_size >= HIGH_BIT_DEPTH_RGB_CHANNEL_BIT_DEPTH);
+        attribute_flags.set(ContextAttributeFlags::SRGB, self.srgb);
+
+        // Create appropriate context attributes. Report the sample count actually granted by the
+        // config, not just the one that was requested, in case `eglChooseConfig` picked a config
+        // with a different (but still matching) sample count.
+        ContextAttributes {
+            flags: attribute_flags,
+            version: self.gl_version,
+            api: self.api,
+            samples: samples as u8,
+        }
     }
 }
 
@@ -235,30 +397,89 @@ impl CurrentContextGuard {
     }
 }
 
-pub(crate) unsafe fn create_context(egl_display: EGLDisplay, descriptor: &ContextDescriptor)
-                                    -> Result<EGLContext, Error> {
+// Creates a context for `descriptor` with no shared context. Equivalent to
+// `create_context(egl_display, descriptor, None)`.
+pub(crate) unsafe fn create_context_without_sharing(egl_display: EGLDisplay,
+                                                    descriptor: &ContextDescriptor)
+                                                    -> Result<(EGLContext, EGLSurface), Error> {
+    create_context(egl_display, descriptor, None)
+}
+
+// Creates a context for `descriptor`, along with the default surface it should be bound to when
+// made current without a real surface attached (see `make_context_current_without_surface`).
+// That default surface is allocated exactly once, here, rather than on every make-current call;
+// callers are responsible for destroying it with `destroy_dummy_pbuffer` when the context goes
+// away. If `share_with` is supplied, the new context will share GL objects (textures, buffers,
+// etc.) with it; both contexts must have been created from the same `EGLDisplay` with the same
+// config, or `Error::IncompatibleSharedContext` is returned.
+pub(crate) unsafe fn create_context(egl_display: EGLDisplay,
+                                    descriptor: &ContextDescriptor,
+                                    share_with: Option<(EGLDisplay, EGLContext)>)
+                                    -> Result<(EGLContext, EGLSurface), Error> {
     let egl_config = egl_config_from_id(egl_display, descriptor.egl_config_id);
 
+    let egl_share_context = match share_with {
+        None => egl::NO_CONTEXT,
+        Some((share_egl_display, egl_share_context)) => {
+            if share_egl_display != egl_display {
+                return Err(Error::IncompatibleSharedContext);
+            }
+            let share_config_id =
+                get_context_attr(egl_display, egl_share_context, egl::CONFIG_ID as EGLint);
+            if share_config_id != descriptor.egl_config_id {
+                return Err(Error::IncompatibleSharedContext);
+            }
+            egl_share_context
+        }
+    };
+
+    let mut egl_context_attributes = vec![
+        egl::CONTEXT_CLIENT_VERSION as EGLint,      descriptor.gl_version.major as EGLint,
+    ];
+    if descriptor.supports_context_minor_version {
+        egl_context_attributes.extend_from_slice(&[
+            EGL_CONTEXT_MINOR_VERSION_KHR,          descriptor.gl_version.minor as EGLint,
+        ]);
+    }
+    if descriptor.robust {
+        egl_context_attributes.extend_from_slice(&[
+            EGL_CONTEXT_OPENGL_ROBUST_ACCESS_EXT,                   egl::TRUE as EGLint,
+            EGL_CONTEXT_OPENGL_RESET_NOTIFICATION_STRATEGY_EXT,     EGL_LOSE_CONTEXT_ON_RESET_EXT,
+        ]);
+    }
+    if descriptor.api == GLApi::GL {
+        egl_context_attributes.extend_from_slice(&[
+            EGL_CONTEXT_OPENGL_PROFILE_MASK_KHR,    EGL_CONTEXT_OPENGL_CORE_PROFILE_BIT_KHR,
+        ]);
+    }
+
     // Include some extra zeroes to work around broken implementations.
     //
     // FIXME(pcwalton): Which implementations are those? (This is copied from Gecko.)
-    let egl_context_attributes = [
-        egl::CONTEXT_CLIENT_VERSION as EGLint,      descriptor.gl_version.major as EGLint,
-        egl::NONE as EGLint, 0,
-        0, 0,
-    ];
+    egl_context_attributes.extend_from_slice(&[egl::NONE as EGLint, 0, 0, 0]);
 
     EGL_FUNCTIONS.with(|egl| {
+        // Bind the client API this context is for. Both APIs can coexist on one display as long
+        // as we rebind before each `eglCreateContext` call.
+        let bind_api_result = match descriptor.api {
+            GLApi::GL => egl.BindAPI(egl::OPENGL_API),
+            GLApi::GLES => egl.BindAPI(egl::OPENGL_ES_API),
+        };
+        if bind_api_result == egl::FALSE {
+            return Err(Error::UnsupportedGLApi);
+        }
+
         let egl_context = egl.CreateContext(egl_display,
                                             egl_config,
-                                            egl::NO_CONTEXT,
+                                            egl_share_context,
                                             egl_context_attributes.as_ptr());
         if egl_context == egl::NO_CONTEXT {
             let err = egl.GetError().to_windowing_api_error();
             return Err(Error::ContextCreationFailed(err));
         }
 
-        Ok(egl_context)
+        let default_surface = default_surface_for_make_current(egl_display, egl_context, descriptor);
+        Ok((egl_context, default_surface))
     })
 }
 
@@ -298,6 +519,24 @@ pub(crate) unsafe fn get_context_attr(egl_display: EGLDisplay,
     })
 }
 
+// Like `get_context_attr`, but for attributes EGL doesn't guarantee are queryable (anything
+// beyond `CONFIG_ID`, `CONTEXT_CLIENT_TYPE`, `CONTEXT_CLIENT_VERSION`, and `RENDER_BUFFER`).
+// Implementations are free to fail an `eglQueryContext` for those, so this reports failure
+// instead of asserting success.
+pub(crate) unsafe fn try_get_context_attr(egl_display: EGLDisplay,
+                                          egl_context: EGLContext,
+                                          attr: EGLint)
+                                          -> Option<EGLint> {
+    EGL_FUNCTIONS.with(|egl| {
+        let mut value = 0;
+        let result = egl.QueryContext(egl_display, egl_context, attr, &mut value);
+        if result == egl::FALSE {
+            return None;
+        }
+        Some(value)
+    })
+}
+
 pub(crate) unsafe fn egl_config_from_id(egl_display: EGLDisplay, egl_config_id: EGLint)
                                         -> EGLConfig {
     let config_attributes = [
@@ -319,6 +558,35 @@ pub(crate) unsafe fn egl_config_from_id(egl_display: EGLDisplay, egl_config_id:
     })
 }
 
+// Returns the space-separated list of EGL extension strings that `eglQueryString` reports for
+// the given display.
+pub(crate) unsafe fn egl_extensions(egl_display: EGLDisplay) -> String {
+    EGL_FUNCTIONS.with(|egl| {
+        let extensions = egl.QueryString(egl_display, egl::EXTENSIONS as EGLint);
+        if extensions.is_null() {
+            return String::new();
+        }
+        CStr::from_ptr(extensions as *const c_char).to_string_lossy().into_owned()
+    })
+}
+
+// Checks whether `name` is present in a space-separated EGL extension string, as returned by
+// `egl_extensions()`.
+pub(crate) fn extension_supported(extensions: &str, name: &str) -> bool {
+    extensions.split_whitespace().any(|extension| extension == name)
+}
+
+// Returns the extra attributes that per-backend surface creation should append to its
+// `eglCreateWindowSurface`/`eglCreatePbufferSurface` attribute list to request an sRGB-encoded
+// framebuffer, or an empty list if `descriptor.srgb` wasn't requested.
+pub(crate) fn srgb_surface_attributes(descriptor: &ContextDescriptor) -> Vec<EGLint> {
+    if descriptor.srgb {
+        vec![EGL_GL_COLORSPACE_KHR, EGL_GL_COLORSPACE_SRGB_KHR]
+    } else {
+        Vec::new()
+    }
+}
+
 pub(crate) fn get_proc_address(symbol_name: &str) -> *const c_void {
     EGL_FUNCTIONS.with(|egl| {
         unsafe {
@@ -328,20 +596,69 @@ pub(crate) fn get_proc_address(symbol_name: &str) -> *const c_void {
     })
 }
 
+// Makes `egl_context` current with no real surface bound, passing `egl_surface` as both the read
+// and draw surface. `egl_surface` should be the default surface `create_context` allocated
+// alongside this context (via `default_surface_for_make_current`) — `egl::NO_SURFACE` for
+// surfaceless contexts, or a dummy pbuffer otherwise — not a fresh one per call, so that no
+// surface gets leaked.
+pub(crate) unsafe fn make_context_current_without_surface(egl_display: EGLDisplay,
+                                                          egl_context: EGLContext,
+                                                          egl_surface: EGLSurface)
+                                                          -> Result<(), Error> {
+    EGL_FUNCTIONS.with(|egl| {
+        let result = egl.MakeCurrent(egl_display, egl_surface, egl_surface, egl_context);
+        if result == egl::FALSE {
+            let err = egl.GetError().to_windowing_api_error();
+            return Err(Error::MakeCurrentFailed(err));
+        }
+        Ok(())
+    })
+}
+
+// Returns the EGL surface that should be bound as both the read and draw surface when making
+// `descriptor`'s context current and no real surface is attached. Surfaceless contexts can be
+// made current against `egl::NO_SURFACE`, exactly like the null context; all other contexts still
+// need a dummy pbuffer to have a default framebuffer to render to. Called once, by
+// `create_context`; the result should be stored and passed to
+// `make_context_current_without_surface` rather than recomputed on every make-current call.
+unsafe fn default_surface_for_make_current(egl_display: EGLDisplay,
+                                           egl_context: EGLContext,
+                                           descriptor: &ContextDescriptor)
+                                           -> EGLSurface {
+    if descriptor.surfaceless {
+        egl::NO_SURFACE
+    } else {
+        create_dummy_pbuffer(egl_display, egl_context, descriptor)
+    }
+}
+
+// Destroys a default surface previously returned by `create_context`. A no-op for surfaceless
+// contexts, whose default surface is `egl::NO_SURFACE`.
+pub(crate) unsafe fn destroy_dummy_pbuffer(egl_display: EGLDisplay, egl_surface: EGLSurface) {
+    if egl_surface == egl::NO_SURFACE {
+        return;
+    }
+    EGL_FUNCTIONS.with(|egl| {
+        let result = egl.DestroySurface(egl_display, egl_surface);
+        assert_ne!(result, egl::FALSE);
+    })
+}
+
 // Creates and returns a dummy pbuffer surface for the given context. This is used as the default
 // framebuffer on some backends.
-#[allow(dead_code)]
-pub(crate) unsafe fn create_dummy_pbuffer(egl_display: EGLDisplay, egl_context: EGLContext)
+pub(crate) unsafe fn create_dummy_pbuffer(egl_display: EGLDisplay,
+                                          egl_context: EGLContext,
+                                          descriptor: &ContextDescriptor)
                                           -> EGLSurface {
     let egl_config_id = get_context_attr(egl_display, egl_context, egl::CONFIG_ID as EGLint);
     let egl_config = egl_config_from_id(egl_display, egl_config_id);
 
-    let pbuffer_attributes = [
+    let mut pbuffer_attributes = vec![
         egl::WIDTH as EGLint,   DUMMY_PBUFFER_SIZE,
         egl::HEIGHT as EGLint,  DUMMY_PBUFFER_SIZE,
-        egl::NONE as EGLint,    0,
-        0,                      0,
     ];
+    pbuffer_attributes.extend_from_slice(&srgb_surface_attributes(descriptor));
+    pbuffer_attributes.extend_from_slice(&[egl::NONE as EGLint, 0, 0, 0]);
 
     EGL_FUNCTIONS.with(|egl| {
         let pbuffer = egl.CreatePbufferSurface(egl_display,
@@ -0,0 +1,9 @@
+// surfman/surfman/src/platform/generic/egl/device.rs
+//
+//! The thread-local EGL function table shared by the context, surface, and device code.
+
+use crate::egl::Egl;
+
+thread_local! {
+    pub(crate) static EGL_FUNCTIONS: Egl = Egl;
+}
@@ -0,0 +1,17 @@
+// surfman/surfman/src/platform/generic/egl/error.rs
+//
+//! Converts EGL error codes into `surfman`'s opaque `WindowingApiError`.
+
+use crate::egl::types::EGLint;
+use crate::WindowingApiError;
+
+/// Converts the result of `eglGetError()` into a `WindowingApiError`.
+pub(crate) trait ToWindowingApiError {
+    fn to_windowing_api_error(self) -> WindowingApiError;
+}
+
+impl ToWindowingApiError for EGLint {
+    fn to_windowing_api_error(self) -> WindowingApiError {
+        WindowingApiError(self as u32)
+    }
+}
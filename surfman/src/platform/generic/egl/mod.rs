@@ -0,0 +1,7 @@
+// surfman/surfman/src/platform/generic/egl/mod.rs
+//
+//! Functionality common to backends using EGL contexts.
+
+pub mod context;
+pub(crate) mod device;
+pub(crate) mod error;
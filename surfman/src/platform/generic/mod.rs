@@ -0,0 +1,5 @@
+// surfman/surfman/src/platform/generic/mod.rs
+//
+//! Backend functionality shared by more than one platform.
+
+pub mod egl;